@@ -0,0 +1,115 @@
+use crate::{error::Error, value::Value};
+
+/// The name of the Ruby encoding a `String` is tagged with, as reported by
+/// `String#encoding`.
+///
+/// This is distinct from [`TryConvert`](crate::TryConvert) for
+/// `std::string::String`, which always transcodes to UTF-8 (replacing
+/// invalid sequences) regardless of the source encoding.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Encoding(String);
+
+impl Encoding {
+    /// Returns the encoding's name, e.g. `"UTF-8"` or `"ISO-8859-1"`.
+    pub fn name(&self) -> &str {
+        &self.0
+    }
+}
+
+/// The raw bytes of a Ruby `String`, paired with the encoding they are
+/// tagged with, with no transcoding applied.
+#[derive(Debug, Clone)]
+pub struct EncodedBytes {
+    bytes: Vec<u8>,
+    encoding: Encoding,
+}
+
+impl EncodedBytes {
+    /// Returns the original encoding of the `String` these bytes were read
+    /// from.
+    pub fn encoding(&self) -> &Encoding {
+        &self.encoding
+    }
+
+    /// Returns the raw bytes of the `String`, untouched by any transcoding.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+/// Returns the name of the Ruby encoding `val` (a `String`) is tagged with.
+///
+/// # Examples
+///
+/// ```
+/// use magnus::{encoding::encoding_of, eval};
+/// # let _cleanup = unsafe { magnus::embed::init() };
+///
+/// let val = eval(r#""caf\xE9".force_encoding("ISO-8859-1")"#).unwrap();
+/// assert_eq!(encoding_of(val).unwrap().name(), "ISO-8859-1");
+/// ```
+pub fn encoding_of(val: Value) -> Result<Encoding, Error> {
+    let name = val.funcall::<_, _, Value>("encoding", ())?.funcall("to_s", ())?;
+    Ok(Encoding(name))
+}
+
+/// Reads the raw bytes of the Ruby `String` `val`, paired with its tagged
+/// encoding, without transcoding to UTF-8.
+///
+/// Useful for handling binary or non-UTF-8 data (e.g. `ASCII-8BIT`) that
+/// would otherwise be silently (and lossily) transcoded by
+/// `try_convert::<String>()`.
+///
+/// # Examples
+///
+/// ```
+/// use magnus::{encoding::raw_bytes, eval};
+/// # let _cleanup = unsafe { magnus::embed::init() };
+///
+/// let val = eval(r#""caf\xE9".force_encoding("ISO-8859-1")"#).unwrap();
+/// let raw = raw_bytes(val).unwrap();
+/// assert_eq!(raw.encoding().name(), "ISO-8859-1");
+/// assert_eq!(raw.as_bytes(), b"caf\xE9");
+/// ```
+pub fn raw_bytes(val: Value) -> Result<EncodedBytes, Error> {
+    let bytes = val.funcall("b", ())?;
+    let encoding = encoding_of(val)?;
+    Ok(EncodedBytes { bytes, encoding })
+}
+
+/// Transcodes the Ruby `String` `val` into `target_encoding`, returning a
+/// Rust `String`.
+///
+/// Unlike `try_convert::<String>()`, which always transcodes to UTF-8 and
+/// replaces invalid byte sequences, this raises (as an `Error`) if `val`
+/// contains sequences invalid for its tagged encoding, or if no conversion
+/// to `target_encoding` is defined, rather than silently losing data.
+///
+/// `String#encode` skips this validation entirely when `target_encoding`
+/// already matches `val`'s tagged encoding (it's a no-op in that case), so
+/// this always routes through a distinct intermediate encoding first to
+/// force Ruby to actually validate `val`, regardless of `target_encoding`.
+///
+/// # Examples
+///
+/// ```
+/// use magnus::{encoding::transcode_to_string, eval};
+/// # let _cleanup = unsafe { magnus::embed::init() };
+///
+/// let val = eval(r#""caf\xE9".force_encoding("ISO-8859-1")"#).unwrap();
+/// assert_eq!(transcode_to_string(val, "UTF-8").unwrap(), "café");
+/// ```
+pub fn transcode_to_string(val: Value, target_encoding: &str) -> Result<String, Error> {
+    // Picking a fixed intermediate would itself be skipped as a no-op if it
+    // happened to match `val`'s own tagged encoding, so choose one of two
+    // candidates based on that tag to guarantee the first hop is always a
+    // real (validating) conversion.
+    let source_encoding = encoding_of(val)?;
+    let intermediate = if source_encoding.name() == "UTF-32BE" {
+        "UTF-32LE"
+    } else {
+        "UTF-32BE"
+    };
+    let hop: Value = val.funcall("encode", (intermediate,))?;
+    hop.funcall("encode", (target_encoding,))
+}
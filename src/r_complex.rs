@@ -16,6 +16,46 @@ use crate::{
     value::{private, NonZeroValue, ReprValue, Value},
 };
 
+/// Complex addition on decomposed `(real, imag)` pairs.
+fn c_add(a: (f64, f64), b: (f64, f64)) -> (f64, f64) {
+    (a.0 + b.0, a.1 + b.1)
+}
+
+/// Complex subtraction on decomposed `(real, imag)` pairs.
+fn c_sub(a: (f64, f64), b: (f64, f64)) -> (f64, f64) {
+    (a.0 - b.0, a.1 - b.1)
+}
+
+/// Complex multiplication on decomposed `(real, imag)` pairs.
+fn c_mul(a: (f64, f64), b: (f64, f64)) -> (f64, f64) {
+    (a.0 * b.0 - a.1 * b.1, a.0 * b.1 + a.1 * b.0)
+}
+
+/// Complex division on decomposed `(real, imag)` pairs.
+fn c_div(a: (f64, f64), b: (f64, f64)) -> (f64, f64) {
+    let denom = b.0 * b.0 + b.1 * b.1;
+    (
+        (a.0 * b.0 + a.1 * b.1) / denom,
+        (a.1 * b.0 - a.0 * b.1) / denom,
+    )
+}
+
+/// Scales a decomposed `(real, imag)` pair by a real factor.
+fn c_scale(a: (f64, f64), k: f64) -> (f64, f64) {
+    (a.0 * k, a.1 * k)
+}
+
+/// `e` raised to the decomposed complex number `a`.
+fn c_exp(a: (f64, f64)) -> (f64, f64) {
+    let r = a.0.exp();
+    (r * a.1.cos(), r * a.1.sin())
+}
+
+/// Principal natural logarithm of the decomposed complex number `a`.
+fn c_ln(a: (f64, f64)) -> (f64, f64) {
+    (a.0.hypot(a.1).ln(), a.1.atan2(a.0))
+}
+
 /// A Value pointer to a RComplex struct, Ruby's internal representation of
 /// complex numbers.
 ///
@@ -171,6 +211,373 @@ impl RComplex {
     pub fn arg(self) -> f64 {
         unsafe { Float::from_rb_value_unchecked(rb_complex_arg(self.as_rb_value())).to_f64() }
     }
+
+    /// Decomposes `self` into its real and imaginary parts as `f64`.
+    fn parts(self) -> (f64, f64) {
+        (
+            self.real::<f64>().expect("Complex real part is numeric"),
+            self.imag::<f64>().expect("Complex imaginary part is numeric"),
+        )
+    }
+
+    /// Builds a new `RComplex` from a decomposed `(real, imag)` pair.
+    fn from_parts(parts: (f64, f64)) -> Self {
+        Self::new(Float::from_f64(parts.0), Float::from_f64(parts.1))
+    }
+
+    /// Returns `e` raised to the power of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{Integer, RComplex};
+    /// # let _cleanup = unsafe { magnus::embed::init() };
+    ///
+    /// let complex = RComplex::new(Integer::from_i64(0), Integer::from_i64(0));
+    /// let result = complex.exp();
+    /// assert_eq!(result.real::<f64>().unwrap(), 1.0);
+    /// assert_eq!(result.imag::<f64>().unwrap(), 0.0);
+    /// ```
+    pub fn exp(self) -> Self {
+        Self::from_parts(c_exp(self.parts()))
+    }
+
+    /// Returns the principal natural logarithm of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{Integer, RComplex};
+    /// # let _cleanup = unsafe { magnus::embed::init() };
+    ///
+    /// let complex = RComplex::new(Integer::from_i64(1), Integer::from_i64(0));
+    /// let result = complex.ln();
+    /// assert_eq!(result.real::<f64>().unwrap(), 0.0);
+    /// assert_eq!(result.imag::<f64>().unwrap(), 0.0);
+    /// ```
+    pub fn ln(self) -> Self {
+        Self::from_parts(c_ln(self.parts()))
+    }
+
+    /// Returns the logarithm of `self` with respect to the given real `base`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{Integer, RComplex};
+    /// # let _cleanup = unsafe { magnus::embed::init() };
+    ///
+    /// let complex = RComplex::new(Integer::from_i64(8), Integer::from_i64(0));
+    /// let result = complex.log(2.0);
+    /// assert!((result.real::<f64>().unwrap() - 3.0).abs() < 1e-9);
+    /// assert!(result.imag::<f64>().unwrap().abs() < 1e-9);
+    /// ```
+    pub fn log(self, base: f64) -> Self {
+        let (re, im) = c_ln(self.parts());
+        let d = base.ln();
+        Self::from_parts((re / d, im / d))
+    }
+
+    /// Returns the principal square root of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{Integer, RComplex};
+    /// # let _cleanup = unsafe { magnus::embed::init() };
+    ///
+    /// let complex = RComplex::new(Integer::from_i64(4), Integer::from_i64(0));
+    /// let result = complex.sqrt();
+    /// assert_eq!(result.real::<f64>().unwrap(), 2.0);
+    /// assert_eq!(result.imag::<f64>().unwrap(), 0.0);
+    /// ```
+    pub fn sqrt(self) -> Self {
+        let (re, im) = self.parts();
+        let r = re.hypot(im).sqrt();
+        let theta = im.atan2(re) / 2.0;
+        Self::from_parts((r * theta.cos(), r * theta.sin()))
+    }
+
+    /// Returns the principal cube root of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{Integer, RComplex};
+    /// # let _cleanup = unsafe { magnus::embed::init() };
+    ///
+    /// let complex = RComplex::new(Integer::from_i64(8), Integer::from_i64(0));
+    /// let result = complex.cbrt();
+    /// assert_eq!(result.real::<f64>().unwrap(), 2.0);
+    /// assert_eq!(result.imag::<f64>().unwrap(), 0.0);
+    /// ```
+    pub fn cbrt(self) -> Self {
+        let (re, im) = self.parts();
+        let r = re.hypot(im).cbrt();
+        let theta = im.atan2(re) / 3.0;
+        Self::from_parts((r * theta.cos(), r * theta.sin()))
+    }
+
+    /// Raises `self` to a complex power.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{Integer, RComplex};
+    /// # let _cleanup = unsafe { magnus::embed::init() };
+    ///
+    /// let complex = RComplex::new(Integer::from_i64(2), Integer::from_i64(0));
+    /// let exponent = RComplex::new(Integer::from_i64(2), Integer::from_i64(0));
+    /// let result = complex.powc(exponent);
+    /// assert!((result.real::<f64>().unwrap() - 4.0).abs() < 1e-9);
+    /// assert!(result.imag::<f64>().unwrap().abs() < 1e-9);
+    /// ```
+    pub fn powc(self, exponent: Self) -> Self {
+        let l = c_ln(self.parts());
+        Self::from_parts(c_exp(c_mul(exponent.parts(), l)))
+    }
+
+    /// Raises `self` to a real power.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{Integer, RComplex};
+    /// # let _cleanup = unsafe { magnus::embed::init() };
+    ///
+    /// let complex = RComplex::new(Integer::from_i64(4), Integer::from_i64(0));
+    /// let result = complex.powf(0.5);
+    /// assert!((result.real::<f64>().unwrap() - 2.0).abs() < 1e-9);
+    /// assert!(result.imag::<f64>().unwrap().abs() < 1e-9);
+    /// ```
+    pub fn powf(self, exponent: f64) -> Self {
+        let l = c_ln(self.parts());
+        Self::from_parts(c_exp(c_scale(l, exponent)))
+    }
+
+    /// Raises `self` to an integer power, by repeated squaring.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{Integer, RComplex};
+    /// # let _cleanup = unsafe { magnus::embed::init() };
+    ///
+    /// let complex = RComplex::new(Integer::from_i64(2), Integer::from_i64(0));
+    /// let result = complex.powi(2);
+    /// assert_eq!(result.real::<f64>().unwrap(), 4.0);
+    /// assert_eq!(result.imag::<f64>().unwrap(), 0.0);
+    /// ```
+    pub fn powi(self, exponent: i32) -> Self {
+        let negative = exponent < 0;
+        let mut exp = exponent.unsigned_abs();
+        let mut base = self.parts();
+        let mut result = (1.0, 0.0);
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = c_mul(result, base);
+            }
+            base = c_mul(base, base);
+            exp >>= 1;
+        }
+        if negative {
+            result = c_div((1.0, 0.0), result);
+        }
+        Self::from_parts(result)
+    }
+
+    /// Returns the sine of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{Integer, RComplex};
+    /// # let _cleanup = unsafe { magnus::embed::init() };
+    ///
+    /// let complex = RComplex::new(Integer::from_i64(0), Integer::from_i64(0));
+    /// let result = complex.sin();
+    /// assert_eq!(result.real::<f64>().unwrap(), 0.0);
+    /// assert_eq!(result.imag::<f64>().unwrap(), 0.0);
+    /// ```
+    pub fn sin(self) -> Self {
+        let (re, im) = self.parts();
+        Self::from_parts((re.sin() * im.cosh(), re.cos() * im.sinh()))
+    }
+
+    /// Returns the cosine of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{Integer, RComplex};
+    /// # let _cleanup = unsafe { magnus::embed::init() };
+    ///
+    /// let complex = RComplex::new(Integer::from_i64(0), Integer::from_i64(0));
+    /// let result = complex.cos();
+    /// assert_eq!(result.real::<f64>().unwrap(), 1.0);
+    /// assert_eq!(result.imag::<f64>().unwrap(), 0.0);
+    /// ```
+    pub fn cos(self) -> Self {
+        let (re, im) = self.parts();
+        Self::from_parts((re.cos() * im.cosh(), -(re.sin() * im.sinh())))
+    }
+
+    /// Returns the tangent of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{Integer, RComplex};
+    /// # let _cleanup = unsafe { magnus::embed::init() };
+    ///
+    /// let complex = RComplex::new(Integer::from_i64(0), Integer::from_i64(0));
+    /// let result = complex.tan();
+    /// assert_eq!(result.real::<f64>().unwrap(), 0.0);
+    /// assert_eq!(result.imag::<f64>().unwrap(), 0.0);
+    /// ```
+    pub fn tan(self) -> Self {
+        let (re, im) = self.parts();
+        let s = (re.sin() * im.cosh(), re.cos() * im.sinh());
+        let c = (re.cos() * im.cosh(), -(re.sin() * im.sinh()));
+        Self::from_parts(c_div(s, c))
+    }
+
+    /// Returns the arcsine of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{Integer, RComplex};
+    /// # let _cleanup = unsafe { magnus::embed::init() };
+    ///
+    /// let complex = RComplex::new(Integer::from_i64(0), Integer::from_i64(0));
+    /// let result = complex.asin();
+    /// assert_eq!(result.real::<f64>().unwrap(), 0.0);
+    /// assert_eq!(result.imag::<f64>().unwrap(), 0.0);
+    ///
+    /// // Real part past the branch cut (`|real| > 1`): the naive
+    /// // `-i·ln(iz+sqrt(1-z²))` composition flips the sign of the
+    /// // imaginary part here, so this is checked against the
+    /// // `num_complex`/C99 principal value directly.
+    /// let complex = RComplex::new(Integer::from_i64(5), Integer::from_i64(0));
+    /// let result = complex.asin();
+    /// assert!((result.real::<f64>().unwrap() - std::f64::consts::FRAC_PI_2).abs() < 1e-9);
+    /// assert!((result.imag::<f64>().unwrap() - 5.0_f64.acosh()).abs() < 1e-9);
+    /// ```
+    pub fn asin(self) -> Self {
+        // Hull, Fairgrieve & Tang (1997) branch-aware decomposition, rather
+        // than the naive `-i·ln(iz+sqrt(1-z²))` composition, which silently
+        // picks the wrong sign for the imaginary part once `z` crosses the
+        // branch cut (`|real(z)| > 1`).
+        let (x, y) = self.parts();
+        let rho1 = (x + 1.0).hypot(y);
+        let rho2 = (x - 1.0).hypot(y);
+        let a = 0.5 * (rho1 + rho2);
+        let b = (0.5 * (rho1 - rho2)).clamp(-1.0, 1.0);
+        let sign = if y.is_sign_negative() { -1.0 } else { 1.0 };
+        Self::from_parts((b.asin(), sign * a.max(1.0).acosh()))
+    }
+
+    /// Returns the arccosine of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{Integer, RComplex};
+    /// # let _cleanup = unsafe { magnus::embed::init() };
+    ///
+    /// let complex = RComplex::new(Integer::from_i64(0), Integer::from_i64(0));
+    /// let result = complex.acos();
+    /// assert_eq!(result.real::<f64>().unwrap(), std::f64::consts::FRAC_PI_2);
+    /// assert_eq!(result.imag::<f64>().unwrap(), 0.0);
+    ///
+    /// // Real part past the branch cut (`|real| > 1`).
+    /// let complex = RComplex::new(Integer::from_i64(5), Integer::from_i64(0));
+    /// let result = complex.acos();
+    /// assert!(result.real::<f64>().unwrap().abs() < 1e-9);
+    /// assert!((result.imag::<f64>().unwrap() + 5.0_f64.acosh()).abs() < 1e-9);
+    /// ```
+    pub fn acos(self) -> Self {
+        let (re, im) = self.asin().parts();
+        Self::from_parts((std::f64::consts::FRAC_PI_2 - re, -im))
+    }
+
+    /// Returns the arctangent of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{Integer, RComplex};
+    /// # let _cleanup = unsafe { magnus::embed::init() };
+    ///
+    /// let complex = RComplex::new(Integer::from_i64(0), Integer::from_i64(0));
+    /// let result = complex.atan();
+    /// assert_eq!(result.real::<f64>().unwrap(), 0.0);
+    /// assert_eq!(result.imag::<f64>().unwrap(), 0.0);
+    /// ```
+    pub fn atan(self) -> Self {
+        let z = self.parts();
+        let iz = (-z.1, z.0);
+        let diff = c_sub(c_ln(c_sub((1.0, 0.0), iz)), c_ln(c_add((1.0, 0.0), iz)));
+        // (i / 2) * diff
+        Self::from_parts((-diff.1 / 2.0, diff.0 / 2.0))
+    }
+
+    /// Returns the hyperbolic sine of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{Integer, RComplex};
+    /// # let _cleanup = unsafe { magnus::embed::init() };
+    ///
+    /// let complex = RComplex::new(Integer::from_i64(0), Integer::from_i64(0));
+    /// let result = complex.sinh();
+    /// assert_eq!(result.real::<f64>().unwrap(), 0.0);
+    /// assert_eq!(result.imag::<f64>().unwrap(), 0.0);
+    /// ```
+    pub fn sinh(self) -> Self {
+        let (re, im) = self.parts();
+        Self::from_parts((re.sinh() * im.cos(), re.cosh() * im.sin()))
+    }
+
+    /// Returns the hyperbolic cosine of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{Integer, RComplex};
+    /// # let _cleanup = unsafe { magnus::embed::init() };
+    ///
+    /// let complex = RComplex::new(Integer::from_i64(0), Integer::from_i64(0));
+    /// let result = complex.cosh();
+    /// assert_eq!(result.real::<f64>().unwrap(), 1.0);
+    /// assert_eq!(result.imag::<f64>().unwrap(), 0.0);
+    /// ```
+    pub fn cosh(self) -> Self {
+        let (re, im) = self.parts();
+        Self::from_parts((re.cosh() * im.cos(), re.sinh() * im.sin()))
+    }
+
+    /// Returns the hyperbolic tangent of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{Integer, RComplex};
+    /// # let _cleanup = unsafe { magnus::embed::init() };
+    ///
+    /// let complex = RComplex::new(Integer::from_i64(0), Integer::from_i64(0));
+    /// let result = complex.tanh();
+    /// assert_eq!(result.real::<f64>().unwrap(), 0.0);
+    /// assert_eq!(result.imag::<f64>().unwrap(), 0.0);
+    /// ```
+    pub fn tanh(self) -> Self {
+        let (re, im) = self.parts();
+        let s = (re.sinh() * im.cos(), re.cosh() * im.sin());
+        let c = (re.cosh() * im.cos(), re.sinh() * im.sin());
+        Self::from_parts(c_div(s, c))
+    }
 }
 
 impl Deref for RComplex {
@@ -231,3 +638,46 @@ impl TryConvert for RComplex {
         })
     }
 }
+
+#[cfg(feature = "num-complex")]
+impl RComplex {
+    /// Converts `self` into a [`num_complex::Complex64`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{Integer, RComplex};
+    /// use num_complex::Complex;
+    /// # let _cleanup = unsafe { magnus::embed::init() };
+    ///
+    /// let complex = RComplex::new(Integer::from_i64(9), Integer::from_i64(-4));
+    /// assert_eq!(complex.to_complex64(), Complex::new(9.0, -4.0));
+    /// ```
+    pub fn to_complex64(self) -> num_complex::Complex<f64> {
+        num_complex::Complex::new(
+            self.real().expect("Complex real part is numeric"),
+            self.imag().expect("Complex imaginary part is numeric"),
+        )
+    }
+}
+
+#[cfg(feature = "num-complex")]
+impl From<num_complex::Complex<f64>> for RComplex {
+    fn from(val: num_complex::Complex<f64>) -> Self {
+        RComplex::new(Float::from_f64(val.re), Float::from_f64(val.im))
+    }
+}
+
+#[cfg(feature = "num-complex")]
+impl IntoValue for num_complex::Complex<f64> {
+    fn into_value_with(self, handle: &RubyHandle) -> Value {
+        RComplex::from(self).into_value_with(handle)
+    }
+}
+
+#[cfg(feature = "num-complex")]
+impl TryConvert for num_complex::Complex<f64> {
+    fn try_convert(val: Value) -> Result<Self, Error> {
+        RComplex::try_convert(val).map(RComplex::to_complex64)
+    }
+}
@@ -1,8 +1,13 @@
 use std::{fmt, ops::Deref};
 
+use rb_sys::{rb_reg_match_post, rb_reg_match_pre, rb_reg_nth_match};
+
 use crate::{
     object::Object,
+    r_array::RArray,
+    r_string::RString,
     ruby_sys::ruby_value_type,
+    try_convert::TryConvert,
     value::{NonZeroValue, Value},
 };
 
@@ -18,6 +23,127 @@ impl RMatch {
                 .then(|| Self(NonZeroValue::new_unchecked(val)))
         }
     }
+
+    /// Returns the string captured by the group at `index`, or `None` if
+    /// that group did not participate in the match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{eval, RMatch};
+    /// # let _cleanup = unsafe { magnus::embed::init() };
+    ///
+    /// let m: RMatch = eval(r#"/(\d+)-(\d+)/.match("12-34")"#).unwrap();
+    /// assert_eq!(m.nth(1).unwrap().to_string(), "12");
+    /// assert_eq!(m.nth(2).unwrap().to_string(), "34");
+    /// ```
+    pub fn nth(self, index: usize) -> Option<RString> {
+        let index = i32::try_from(index).ok()?;
+        unsafe { Value::new(rb_reg_nth_match(index, self.as_rb_value())) }
+            .try_convert()
+            .ok()
+    }
+
+    /// Returns the string captured by the named group `name`, or `None` if
+    /// that group does not exist or did not participate in the match.
+    ///
+    /// There is no public `MatchData` C API for named-group lookup (it
+    /// requires resolving the backreference through the `Regexp`'s named
+    /// capture table), so this dispatches through `MatchData#[]` instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{eval, RMatch};
+    /// # let _cleanup = unsafe { magnus::embed::init() };
+    ///
+    /// let m: RMatch = eval(r#"/(?<year>\d+)-(?<month>\d+)/.match("2023-04")"#).unwrap();
+    /// assert_eq!(m.named("year").unwrap().to_string(), "2023");
+    /// ```
+    pub fn named(self, name: &str) -> Option<RString> {
+        self.funcall("[]", (name,)).ok()
+    }
+
+    /// Returns the portion of the original string before the match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{eval, RMatch};
+    /// # let _cleanup = unsafe { magnus::embed::init() };
+    ///
+    /// let m: RMatch = eval(r#"/b/.match("abc")"#).unwrap();
+    /// assert_eq!(m.pre_match().to_string(), "a");
+    /// ```
+    pub fn pre_match(self) -> RString {
+        unsafe { Value::new(rb_reg_match_pre(self.as_rb_value())) }
+            .try_convert()
+            .expect("pre_match is a String for any successful MatchData")
+    }
+
+    /// Returns the portion of the original string after the match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{eval, RMatch};
+    /// # let _cleanup = unsafe { magnus::embed::init() };
+    ///
+    /// let m: RMatch = eval(r#"/b/.match("abc")"#).unwrap();
+    /// assert_eq!(m.post_match().to_string(), "c");
+    /// ```
+    pub fn post_match(self) -> RString {
+        unsafe { Value::new(rb_reg_match_post(self.as_rb_value())) }
+            .try_convert()
+            .expect("post_match is a String for any successful MatchData")
+    }
+
+    /// Returns all of the captured strings, with the whole match as the
+    /// first element.
+    ///
+    /// There is no single `MatchData` C API that returns every captured
+    /// string at once, so this dispatches through `MatchData#to_a`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{eval, RMatch};
+    /// # let _cleanup = unsafe { magnus::embed::init() };
+    ///
+    /// let m: RMatch = eval(r#"/(\d+)-(\d+)/.match("12-34")"#).unwrap();
+    /// assert_eq!(m.to_a().len(), 3);
+    /// ```
+    pub fn to_a(self) -> RArray {
+        self.funcall("to_a", ())
+            .expect("to_a is infallible on a MatchData")
+    }
+
+    /// Returns the begin/end byte offsets of the group at `index`, or `None`
+    /// if that group did not participate in the match.
+    ///
+    /// There is no public `MatchData` C API exposing group begin/end
+    /// offsets, so this dispatches through `MatchData#offset`, converting its
+    /// character offsets to byte offsets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{eval, RMatch};
+    /// # let _cleanup = unsafe { magnus::embed::init() };
+    ///
+    /// let m: RMatch = eval(r#"/b/.match("abc")"#).unwrap();
+    /// assert_eq!(m.offset(0), Some((1, 2)));
+    /// ```
+    pub fn offset(self, index: usize) -> Option<(usize, usize)> {
+        let (begin, end): (Option<usize>, Option<usize>) = self.funcall("offset", (index,)).ok()?;
+        let (begin, end) = begin.zip(end)?;
+        let string: Value = self.funcall("string", ()).ok()?;
+        let byte_offset = |chars: usize| -> Option<usize> {
+            let prefix: Value = string.funcall("[]", (0usize, chars)).ok()?;
+            prefix.funcall("bytesize", ()).ok()
+        };
+        Some((byte_offset(begin)?, byte_offset(end)?))
+    }
 }
 
 impl Deref for RMatch {
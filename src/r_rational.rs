@@ -1,22 +1,108 @@
 use std::{fmt, ops::Deref};
 
+use rb_sys::{rb_rational_den, rb_rational_new, rb_rational_num, ruby_value_type, VALUE};
+
 use crate::{
-    r_basic::RBasic,
-    ruby_sys::{ruby_value_type, VALUE},
-    value::Value,
+    error::{protect, Error},
+    exception,
+    into_value::IntoValue,
+    numeric::Numeric,
+    ruby_handle::RubyHandle,
+    try_convert::TryConvert,
+    value::{private, NonZeroValue, ReprValue, Value},
 };
+#[cfg(feature = "num-rational")]
+use crate::integer::Integer;
 
+/// A Value pointer to a RRational struct, Ruby's internal representation of
+/// rational numbers.
+///
+/// All [`Value`] methods should be available on this type through [`Deref`],
+/// but some may be missed by this documentation.
+#[derive(Clone, Copy)]
 #[repr(transparent)]
-pub struct RRational(VALUE);
+pub struct RRational(NonZeroValue);
 
 impl RRational {
-    /// # Safety
+    /// Return `Some(RRational)` if `val` is a `RRational`, `None` otherwise.
+    #[inline]
+    pub fn from_value(val: Value) -> Option<Self> {
+        unsafe {
+            (val.rb_type() == ruby_value_type::RUBY_T_RATIONAL)
+                .then(|| Self(NonZeroValue::new_unchecked(val)))
+        }
+    }
+
+    #[inline]
+    pub(crate) unsafe fn from_rb_value_unchecked(val: VALUE) -> Self {
+        Self(NonZeroValue::new_unchecked(Value::new(val)))
+    }
+
+    /// Create a new `RRational`.
+    ///
+    /// Returns `Err` if `den` is zero, as Ruby's `Rational` construction
+    /// does (this mirrors `Rational(1, 0)` raising `ZeroDivisionError`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{Integer, RRational};
+    /// # let _cleanup = unsafe { magnus::embed::init() };
+    ///
+    /// let rational = RRational::new(Integer::from_i64(2), Integer::from_i64(4)).unwrap();
+    /// assert_eq!(rational.to_string(), "1/2");
     ///
-    /// val must not have been GC'd, return value must be kept on stack or
-    /// otherwise protected from the GC.
-    pub unsafe fn from_value(val: &Value) -> Option<Self> {
-        let r_basic = RBasic::from_value(val)?;
-        (r_basic.builtin_type() == ruby_value_type::RUBY_T_RATIONAL).then(|| Self(val.into_inner()))
+    /// assert!(RRational::new(Integer::from_i64(1), Integer::from_i64(0)).is_err());
+    /// ```
+    pub fn new<T, U>(num: T, den: U) -> Result<RRational, Error>
+    where
+        T: Numeric,
+        U: Numeric,
+    {
+        protect(|| unsafe {
+            RRational::from_rb_value_unchecked(rb_rational_new(
+                num.as_rb_value(),
+                den.as_rb_value(),
+            ))
+        })
+    }
+
+    /// Returns the numerator of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{Integer, RRational};
+    /// # let _cleanup = unsafe { magnus::embed::init() };
+    ///
+    /// let rational = RRational::new(Integer::from_i64(2), Integer::from_i64(4)).unwrap();
+    /// assert_eq!(rational.numerator::<i64>().unwrap(), 1);
+    /// ```
+    pub fn numerator<T>(self) -> Result<T, Error>
+    where
+        T: TryConvert,
+    {
+        let val = unsafe { Value::new(rb_rational_num(self.as_rb_value())) };
+        val.try_convert()
+    }
+
+    /// Returns the denominator of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{Integer, RRational};
+    /// # let _cleanup = unsafe { magnus::embed::init() };
+    ///
+    /// let rational = RRational::new(Integer::from_i64(2), Integer::from_i64(4)).unwrap();
+    /// assert_eq!(rational.denominator::<i64>().unwrap(), 2);
+    /// ```
+    pub fn denominator<T>(self) -> Result<T, Error>
+    where
+        T: TryConvert,
+    {
+        let val = unsafe { Value::new(rb_rational_den(self.as_rb_value())) };
+        val.try_convert()
     }
 }
 
@@ -24,10 +110,7 @@ impl Deref for RRational {
     type Target = Value;
 
     fn deref(&self) -> &Self::Target {
-        let self_ptr = self as *const Self;
-        let value_ptr = self_ptr as *const Self::Target;
-        // we just got this pointer from &self, so we know it's valid to deref
-        unsafe { &*value_ptr }
+        self.0.get_ref()
     }
 }
 
@@ -39,7 +122,13 @@ impl fmt::Display for RRational {
 
 impl fmt::Debug for RRational {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", unsafe { self.inspect() })
+        write!(f, "{}", self.inspect())
+    }
+}
+
+impl IntoValue for RRational {
+    fn into_value_with(self, _: &RubyHandle) -> Value {
+        *self
     }
 }
 
@@ -48,3 +137,199 @@ impl From<RRational> for Value {
         *val
     }
 }
+
+unsafe impl private::ReprValue for RRational {
+    fn to_value(self) -> Value {
+        *self
+    }
+
+    unsafe fn from_value_unchecked(val: Value) -> Self {
+        Self(NonZeroValue::new_unchecked(val))
+    }
+}
+
+impl Numeric for RRational {}
+
+impl ReprValue for RRational {}
+
+impl TryConvert for RRational {
+    fn try_convert(val: Value) -> Result<Self, Error> {
+        Self::from_value(val).ok_or_else(|| {
+            Error::new(
+                exception::type_error(),
+                format!("no implicit conversion of {} into Rational", unsafe {
+                    val.classname()
+                },),
+            )
+        })
+    }
+}
+
+#[cfg(feature = "num-rational")]
+impl RRational {
+    /// Converts `self` into a [`num_rational::Ratio<i64>`].
+    ///
+    /// Returns `Err` if the numerator or denominator don't fit in an `i64`,
+    /// which is possible for an arbitrarily large Ruby `Rational` even
+    /// though `self` is a perfectly valid rational number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{Integer, RRational};
+    /// use num_rational::Ratio;
+    /// # let _cleanup = unsafe { magnus::embed::init() };
+    ///
+    /// let rational = RRational::new(Integer::from_i64(2), Integer::from_i64(4)).unwrap();
+    /// assert_eq!(rational.to_ratio_i64().unwrap(), Ratio::new(1, 2));
+    /// ```
+    pub fn to_ratio_i64(self) -> Result<num_rational::Ratio<i64>, Error> {
+        Ok(num_rational::Ratio::new(
+            self.numerator()?,
+            self.denominator()?,
+        ))
+    }
+
+    /// Finds the simplest `Rational` within `eps` of `value`, by walking the
+    /// continued-fraction convergents of `value`, mirroring Ruby's
+    /// `Float#rationalize`.
+    ///
+    /// Each convergent consumes a whole continued-fraction term at once
+    /// (via `value`'s integer part at each step), rather than taking the
+    /// Stern-Brocot mediant one unary step at a time, so this is `O(log
+    /// value)` rather than `O(value)`: the continued-fraction expansion of
+    /// any finite `f64` has a bounded number of terms regardless of how
+    /// large or small `value` is. The walk is capped at a fixed number of
+    /// terms and bails out to the best convergent found so far if a term
+    /// overflows `i64` or `value`'s reciprocal stops being finite, so this
+    /// always terminates even for inputs (e.g. `value` near or beyond
+    /// `i64::MAX`) that have no exact `i64`-denominator representation.
+    ///
+    /// Once a convergent lands within `eps`, a binary search over the
+    /// semiconvergents of that last term (the mediants stepping towards it
+    /// from the convergent before the previous one) picks the simplest of
+    /// them within `eps`. Where several whole numbers lie within `eps`, this
+    /// returns `value` rounded towards zero, where `Float#rationalize`
+    /// returns the one closest to zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::RRational;
+    /// # let _cleanup = unsafe { magnus::embed::init() };
+    ///
+    /// let rational = RRational::approximate(0.333, 0.001);
+    /// assert_eq!(rational.to_string(), "1/3");
+    ///
+    /// let rational = RRational::approximate(0.0, 1e-15);
+    /// assert_eq!(rational.to_string(), "0/1");
+    ///
+    /// let rational = RRational::approximate(1e18, 0.5);
+    /// assert_eq!(rational.to_string(), "1000000000000000000/1");
+    ///
+    /// let rational = RRational::approximate(1e20, 1000.0);
+    /// assert_eq!(rational.to_string(), "9223372036854775807/1");
+    /// ```
+    pub fn approximate(value: f64, eps: f64) -> RRational {
+        assert!(
+            value.is_finite(),
+            "cannot rationalize a non-finite value: {}",
+            value
+        );
+
+        // An upper bound on the number of terms walked: `k` grows at least as
+        // fast as the Fibonacci numbers, so by the last of these terms it is
+        // over 10^13.
+        const MAX_CONVERGENTS: u32 = 64;
+
+        let negative = value.is_sign_negative();
+        let value = value.abs();
+
+        // `0/1` is the simplest possible rational: test it directly rather
+        // than walking convergents for any `value` within `eps` of zero.
+        if value <= eps {
+            return RRational::new(Integer::from_i64(0), Integer::from_i64(1))
+                .expect("denominator 1 is never zero");
+        }
+
+        // Convergents h/k of the continued-fraction expansion of `value`,
+        // via the standard recurrence h[n] = a[n]*h[n-1] + h[n-2] (and same
+        // for k), seeded with h[-1]=1, h[-2]=0, k[-1]=0, k[-2]=1.
+        let (mut h_prev2, mut h_prev1) = (0i64, 1i64);
+        let (mut k_prev2, mut k_prev1) = (1i64, 0i64);
+        let mut x = value;
+
+        // Best convergent found so far, in case we have to bail out before
+        // landing within `eps`: either the loop exhausts `MAX_CONVERGENTS`,
+        // or a term doesn't fit in `i64` (certain once `x` exceeds
+        // `i64::MAX`, which `value` alone can on its first iteration), or
+        // `x`'s reciprocal stops being finite (e.g. `frac` rounds to `0.0`).
+        let mut best: Option<(i64, i64)> = None;
+
+        for _ in 0..MAX_CONVERGENTS {
+            if !x.is_finite() || x.abs() >= i64::MAX as f64 {
+                break;
+            }
+            let a = x.floor() as i64;
+
+            let (h, k) = match (
+                a.checked_mul(h_prev1).and_then(|v| v.checked_add(h_prev2)),
+                a.checked_mul(k_prev1).and_then(|v| v.checked_add(k_prev2)),
+            ) {
+                (Some(h), Some(k)) => (h, k),
+                _ => break,
+            };
+
+            if k != 0 {
+                best = Some((h, k));
+                if (h as f64 / k as f64 - value).abs() <= eps {
+                    // Semiconvergents (t*h[n-1] + h[n-2])/(t*k[n-1] + k[n-2]),
+                    // 0 < t < a, step from h[n-2]/k[n-2] towards h/k, so the
+                    // first within `eps` is the simplest rational in range.
+                    let (mut lo, mut hi) = (0, a);
+                    while k_prev1 != 0 && hi - lo > 1 {
+                        let mid = lo + (hi - lo) / 2;
+                        let (hm, km) = (mid * h_prev1 + h_prev2, mid * k_prev1 + k_prev2);
+                        if (hm as f64 / km as f64 - value).abs() <= eps {
+                            hi = mid;
+                        } else {
+                            lo = mid;
+                        }
+                    }
+                    best = Some((hi * h_prev1 + h_prev2, hi * k_prev1 + k_prev2));
+                    break;
+                }
+            }
+
+            let frac = x - a as f64;
+            if frac == 0.0 {
+                break;
+            }
+            x = 1.0 / frac;
+
+            h_prev2 = h_prev1;
+            h_prev1 = h;
+            k_prev2 = k_prev1;
+            k_prev1 = k;
+        }
+
+        // `best` is always `Some` unless even the first convergent (`value`
+        // rounded down, over 1) overflowed `i64`, in
+        // which case the closest we can represent is `i64::MAX`/`1`.
+        let (num, den) = best.unwrap_or((i64::MAX, 1));
+
+        RRational::new(
+            Integer::from_i64(if negative { -num } else { num }),
+            Integer::from_i64(den),
+        )
+        .expect("denominator is a convergent's k, which is only ever kept in `best` when nonzero")
+    }
+}
+
+#[cfg(feature = "num-rational")]
+impl From<num_rational::Ratio<i64>> for RRational {
+    fn from(val: num_rational::Ratio<i64>) -> Self {
+        RRational::new(Integer::from_i64(*val.numer()), Integer::from_i64(*val.denom()))
+            .expect("num_rational::Ratio never holds a zero denominator")
+    }
+}
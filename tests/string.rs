@@ -1,4 +1,7 @@
-use magnus::eval_static;
+use magnus::{
+    encoding::{raw_bytes, transcode_to_string},
+    eval_static,
+};
 
 #[test]
 fn it_converts_to_utf8_string() {
@@ -9,3 +12,33 @@ fn it_converts_to_utf8_string() {
 
     assert_eq!("café", s);
 }
+
+#[test]
+fn it_preserves_raw_bytes_without_transcoding() {
+    let _cleanup = unsafe { magnus::embed::init() };
+
+    let val = eval_static(r#""caf\xE9".force_encoding("ISO-8859-1")"#).unwrap();
+    let raw = raw_bytes(val).unwrap();
+
+    assert_eq!("ISO-8859-1", raw.encoding().name());
+    assert_eq!(b"caf\xE9", raw.as_bytes());
+}
+
+#[test]
+fn it_transcodes_to_an_explicit_target_encoding() {
+    let _cleanup = unsafe { magnus::embed::init() };
+
+    let val = eval_static(r#""caf\xE9".force_encoding("ISO-8859-1")"#).unwrap();
+    let s = transcode_to_string(val, "UTF-8").unwrap();
+
+    assert_eq!("café", s);
+}
+
+#[test]
+fn it_fails_to_transcode_invalid_byte_sequences() {
+    let _cleanup = unsafe { magnus::embed::init() };
+
+    let val = eval_static(r#""caf\xE9".dup.force_encoding("UTF-8")"#).unwrap();
+
+    assert!(transcode_to_string(val, "UTF-8").is_err());
+}